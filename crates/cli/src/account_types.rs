@@ -0,0 +1,66 @@
+//! A concrete, decodable account type for the `account-dump` CLI command
+//!
+//! This CLI isn't pointed at any one program, so there's no account layout to decode out of the
+//! box; `VaultAccount` stands in for a real program's account until this tree is wired to one —
+//! swap the fields, `PROGRAM_ID`, and seeds below for the target program's own layout.
+
+use {
+    serde::Serialize,
+    solana_sdk::pubkey::Pubkey,
+    sutils::{
+        account::{
+            AccountDeserialize, AccountRead, AccountSerialize, AccountWrite, PdaDeriver, ProgramId,
+        },
+        discriminator::AccountDiscriminator,
+        uint::parse_u64,
+    },
+};
+
+/// A PDA seeded by `[b"vault", owner]`, holding a `u64` balance
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct VaultAccount {
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+impl ProgramId for VaultAccount {
+    const PROGRAM_ID: Pubkey = solana_sdk::pubkey!("LbUiWL3xVV8hTFYBVdbTNrpDo41NKS6o3LHHuDzjfcY");
+}
+
+impl AccountDiscriminator for VaultAccount {
+    const DISCRIMINATOR: u8 = 0;
+}
+
+impl AccountSerialize for VaultAccount {
+    const SERIALIZED_SIZE: usize = 1 // discriminator
+        + 32 // owner
+        + 8; // amount
+
+    fn to_bytes_inner(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::SERIALIZED_SIZE - 1);
+
+        buf.extend_from_slice(&self.owner.to_bytes());
+        buf.extend_from_slice(&self.amount.to_le_bytes());
+
+        buf
+    }
+}
+
+impl AccountDeserialize for VaultAccount {
+    fn from_bytes(data: &[u8]) -> Self {
+        let owner: Pubkey = data[0..32].try_into().expect("insufficient bytes");
+        let amount = parse_u64(&data[32..40]);
+
+        Self { owner, amount }
+    }
+}
+
+impl AccountWrite for VaultAccount {}
+
+impl PdaDeriver for VaultAccount {
+    fn create_pda(&self) -> Pubkey {
+        Self::pda_derive(&[b"vault", self.owner.as_ref()]).0
+    }
+}
+
+impl AccountRead for VaultAccount {}
@@ -15,12 +15,36 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     ConfigInit {},
+    AccountDump {
+        #[arg(long, help = "base58-encoded account address to fetch (ignored with --all)")]
+        address: Option<String>,
+        #[arg(
+            long,
+            help = "decode as a VaultAccount instead of dumping raw owner/lamports/data_len"
+        )]
+        decode: bool,
+        #[arg(long, help = "decode every VaultAccount owned by its program, instead of --address")]
+        all: bool,
+    },
 }
 
 impl Cli {
     pub async fn handle(self) -> Result<()> {
         match self.cmd {
             Commands::ConfigInit {} => commands::config_init::config_init(&self.config).await,
+            Commands::AccountDump {
+                address,
+                decode,
+                all,
+            } => {
+                commands::account_dump::account_dump(
+                    &self.config,
+                    address.as_deref(),
+                    decode,
+                    all,
+                )
+                .await
+            }
         }
     }
 }
@@ -0,0 +1,129 @@
+//! An async RPC client layer for fetching and deserializing on-chain accounts
+//!
+//! Wraps `solana_client`'s non-blocking `RpcClient`, turning the raw bytes it returns into typed
+//! account structs via `sutils::account::AccountRead`, so a CLI command never has to hand-roll
+//! account parsing.
+
+use {
+    anyhow::{Context, Result},
+    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_sdk::{account::Account, account_info::IntoAccountInfo, pubkey::Pubkey},
+    sutils::account::AccountRead,
+};
+
+/// Bridges a fetched `(Pubkey, Account)` into a borrowed `AccountInfo` and decodes it via
+/// [`AccountRead::account_read`], validating the account's owner, discriminator, and PDA address
+///
+/// Factored out of [`fetch_account`]/[`fetch_program_accounts`] so the decode path can be unit
+/// tested without an RPC round trip.
+fn decode_account<T: AccountRead>(pubkey: &Pubkey, account: &mut Account) -> Result<T> {
+    let account_info = (pubkey, account).into_account_info();
+
+    T::account_read(&account_info).with_context(|| format!("failed to decode account {pubkey}"))
+}
+
+/// Connects to `rpc_url` and fetches + deserializes the account at `pubkey`
+pub async fn fetch_account<T: AccountRead>(rpc_url: &str, pubkey: &Pubkey) -> Result<T> {
+    let rpc = RpcClient::new(rpc_url.to_string());
+
+    let mut account = rpc
+        .get_account(pubkey)
+        .await
+        .with_context(|| format!("failed to fetch account {pubkey}"))?;
+
+    decode_account(pubkey, &mut account)
+}
+
+/// Connects to `rpc_url` and fetches + deserializes every account owned by `T::PROGRAM_ID`
+pub async fn fetch_program_accounts<T: AccountRead>(rpc_url: &str) -> Result<Vec<(Pubkey, T)>> {
+    let rpc = RpcClient::new(rpc_url.to_string());
+
+    let accounts = rpc
+        .get_program_accounts(&T::PROGRAM_ID)
+        .await
+        .with_context(|| format!("failed to fetch program accounts for {}", T::PROGRAM_ID))?;
+
+    accounts
+        .into_iter()
+        .map(|(pubkey, mut account)| {
+            let decoded = decode_account(&pubkey, &mut account)?;
+            Ok((pubkey, decoded))
+        })
+        .collect()
+}
+
+/// A JSON-friendly view of an account's raw on-chain state, used by the `account-dump` CLI
+/// command when no concrete [`AccountRead`] type is known ahead of time
+#[derive(serde::Serialize)]
+pub struct RawAccount {
+    pub owner: String,
+    pub lamports: u64,
+    pub data_len: usize,
+    pub executable: bool,
+}
+
+/// Connects to `rpc_url` and fetches the raw account at `pubkey`, without attempting to decode it
+pub async fn fetch_raw_account(rpc_url: &str, pubkey: &Pubkey) -> Result<RawAccount> {
+    let rpc = RpcClient::new(rpc_url.to_string());
+
+    let account = rpc
+        .get_account(pubkey)
+        .await
+        .with_context(|| format!("failed to fetch account {pubkey}"))?;
+
+    Ok(RawAccount {
+        owner: account.owner.to_string(),
+        lamports: account.lamports,
+        data_len: account.data.len(),
+        executable: account.executable,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::account_types::VaultAccount,
+        sutils::account::{AccountSerialize, PdaDeriver, ProgramId},
+    };
+
+    #[test]
+    fn test_decode_account_round_trips() {
+        let vault = VaultAccount {
+            owner: Pubkey::new_unique(),
+            amount: 1337,
+        };
+        let pda = vault.create_pda();
+
+        let mut account = Account {
+            lamports: 0,
+            data: vault.to_bytes().unwrap(),
+            owner: VaultAccount::PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let decoded: VaultAccount = decode_account(&pda, &mut account).unwrap();
+
+        assert_eq!(decoded, vault);
+    }
+
+    #[test]
+    fn test_decode_account_rejects_wrong_owner() {
+        let vault = VaultAccount {
+            owner: Pubkey::new_unique(),
+            amount: 1337,
+        };
+        let pda = vault.create_pda();
+
+        let mut account = Account {
+            lamports: 0,
+            data: vault.to_bytes().unwrap(),
+            owner: Pubkey::new_unique(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        assert!(decode_account::<VaultAccount>(&pda, &mut account).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use {
+    crate::{account_types::VaultAccount, client},
+    anyhow::{bail, Result},
+    config::Configuration,
+    solana_sdk::pubkey::Pubkey,
+    std::str::FromStr,
+};
+
+pub async fn account_dump(
+    config_path: &str,
+    address: Option<&str>,
+    decode: bool,
+    all: bool,
+) -> Result<()> {
+    let config = Configuration::load(config_path).await?;
+
+    if all {
+        let accounts = client::fetch_program_accounts::<VaultAccount>(&config.rpc_url).await?;
+        println!("{}", serde_json::to_string_pretty(&accounts)?);
+        return Ok(());
+    }
+
+    let Some(address) = address else {
+        bail!("--address is required unless --all is set");
+    };
+    let pubkey = Pubkey::from_str(address)?;
+
+    if decode {
+        let account: VaultAccount = client::fetch_account(&config.rpc_url, &pubkey).await?;
+        println!("{}", serde_json::to_string_pretty(&account)?);
+    } else {
+        let account = client::fetch_raw_account(&config.rpc_url, &pubkey).await?;
+        println!("{}", serde_json::to_string_pretty(&account)?);
+    }
+
+    Ok(())
+}
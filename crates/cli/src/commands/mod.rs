@@ -0,0 +1,2 @@
+pub mod account_dump;
+pub mod config_init;
@@ -1,6 +1,8 @@
 use {anyhow::Result, clap::Parser, cli::Cli};
 
+mod account_types;
 mod cli;
+mod client;
 mod commands;
 
 #[tokio::main]
@@ -0,0 +1,393 @@
+//! Derive macros companion to `sutils`
+//!
+//! `#[derive(Account)]` and `#[derive(Instruction)]` generate the hand-written impls shown in
+//! `sutils::account` and `sutils::instruction_packer`'s test modules, so offset math for
+//! `SERIALIZED_SIZE`/`to_bytes_inner`/`from_bytes` and instruction pack/unpack match arms no
+//! longer have to be kept in sync by hand.
+
+use {
+    proc_macro::TokenStream,
+    proc_macro2::TokenStream as TokenStream2,
+    quote::quote,
+    syn::{parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Expr, Fields, Token, Type},
+};
+
+enum FieldKind {
+    Pubkey,
+    Bool,
+    UInt(usize),
+    Array(usize),
+    /// A variable-length trailing field (`Vec<u8>`), only valid as an instruction's last field
+    Bytes,
+}
+
+fn classify(ty: &Type) -> Result<FieldKind, TokenStream2> {
+    match ty {
+        Type::Array(arr) => {
+            let is_u8_elem = matches!(
+                &*arr.elem,
+                Type::Path(path) if path.path.is_ident("u8")
+            );
+            if !is_u8_elem {
+                return Err(syn::Error::new_spanned(
+                    &arr.elem,
+                    "array fields must have element type `u8`",
+                )
+                .to_compile_error());
+            }
+
+            if let Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(n),
+                ..
+            }) = &arr.len
+            {
+                let len: usize = n.base10_parse().map_err(|e| e.to_compile_error())?;
+                Ok(FieldKind::Array(len))
+            } else {
+                Err(
+                    syn::Error::new_spanned(&arr.len, "array length must be an integer literal")
+                        .to_compile_error(),
+                )
+            }
+        }
+        Type::Path(path) => {
+            let ident = path
+                .path
+                .segments
+                .last()
+                .map(|s| s.ident.to_string())
+                .unwrap_or_default();
+            match ident.as_str() {
+                "Pubkey" => Ok(FieldKind::Pubkey),
+                "bool" => Ok(FieldKind::Bool),
+                "u8" => Ok(FieldKind::UInt(1)),
+                "u16" => Ok(FieldKind::UInt(2)),
+                "u32" => Ok(FieldKind::UInt(4)),
+                "u64" => Ok(FieldKind::UInt(8)),
+                "u128" => Ok(FieldKind::UInt(16)),
+                "Vec" => Ok(FieldKind::Bytes),
+                other => Err(syn::Error::new_spanned(
+                    ty,
+                    format!("unsupported field type `{other}` for derive"),
+                )
+                .to_compile_error()),
+            }
+        }
+        other => Err(syn::Error::new_spanned(other, "unsupported field type").to_compile_error()),
+    }
+}
+
+impl FieldKind {
+    fn size(&self) -> Option<usize> {
+        match self {
+            FieldKind::Pubkey => Some(32),
+            FieldKind::Bool => Some(1),
+            FieldKind::UInt(n) => Some(*n),
+            FieldKind::Array(n) => Some(*n),
+            FieldKind::Bytes => None,
+        }
+    }
+
+    fn pack_expr(&self, field: &TokenStream2) -> TokenStream2 {
+        match self {
+            FieldKind::Pubkey => quote! { buf.extend_from_slice(&#field.to_bytes()); },
+            FieldKind::Bool => quote! { buf.push(if #field { 1u8 } else { 0u8 }); },
+            FieldKind::UInt(_) => quote! { buf.extend_from_slice(&#field.to_le_bytes()); },
+            FieldKind::Array(_) => quote! { buf.extend_from_slice(&#field); },
+            FieldKind::Bytes => quote! { buf.extend_from_slice(&#field); },
+        }
+    }
+
+    fn unpack_expr(&self, offset: &TokenStream2) -> TokenStream2 {
+        match self {
+            FieldKind::Pubkey => quote! {
+                ::solana_program::pubkey::Pubkey::new_from_array(
+                    data[#offset..#offset + 32].try_into().expect("insufficient bytes")
+                )
+            },
+            FieldKind::Bool => quote! { data[#offset] != 0 },
+            FieldKind::UInt(1) => quote! { data[#offset] },
+            FieldKind::UInt(2) => quote! {
+                u16::from_le_bytes(data[#offset..#offset + 2].try_into().expect("insufficient bytes"))
+            },
+            FieldKind::UInt(4) => quote! {
+                u32::from_le_bytes(data[#offset..#offset + 4].try_into().expect("insufficient bytes"))
+            },
+            FieldKind::UInt(8) => quote! {
+                u64::from_le_bytes(data[#offset..#offset + 8].try_into().expect("insufficient bytes"))
+            },
+            FieldKind::UInt(16) => quote! {
+                u128::from_le_bytes(data[#offset..#offset + 16].try_into().expect("insufficient bytes"))
+            },
+            FieldKind::UInt(_) => unreachable!("unsupported integer width"),
+            FieldKind::Array(n) => quote! {
+                data[#offset..#offset + #n].try_into().expect("insufficient bytes")
+            },
+            FieldKind::Bytes => quote! { data[#offset..].to_vec() },
+        }
+    }
+}
+
+/// Derives [`HashedAccountDiscriminator`], [`HashedAccountSerialize`], and
+/// [`HashedAccountDeserialize`] for a struct of fixed-size fields (`Pubkey`, `bool`, `u8..u128`,
+/// `[u8; N]`), computing `SERIALIZED_SIZE` and generating little-endian field packing/unpacking so
+/// callers no longer hand-write `to_bytes_inner`/`from_bytes` offset math.
+///
+/// A `#[seeds(...)]` struct attribute additionally emits `PdaDeriver::create_pda`, e.g.:
+///
+/// ```ignore
+/// #[derive(Account)]
+/// #[seeds(b"vault", self.owner.as_ref())]
+/// pub struct Vault {
+///     pub owner: Pubkey,
+///     pub amount: u64,
+/// }
+/// ```
+///
+/// [`HashedAccountDiscriminator`]: sutils::discriminator::HashedAccountDiscriminator
+/// [`HashedAccountSerialize`]: sutils::account::HashedAccountSerialize
+/// [`HashedAccountDeserialize`]: sutils::account::HashedAccountDeserialize
+#[proc_macro_derive(Account, attributes(seeds))]
+pub fn derive_account(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return syn::Error::new_spanned(name, "derive(Account) requires named fields")
+                    .to_compile_error()
+                    .into()
+            }
+        },
+        _ => {
+            return syn::Error::new_spanned(name, "derive(Account) only supports structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut kinds = Vec::with_capacity(fields.len());
+    for field in fields {
+        match classify(&field.ty) {
+            Ok(kind) => kinds.push((field.ident.clone().unwrap(), kind)),
+            Err(err) => return err.into(),
+        }
+    }
+
+    let mut size_terms = Vec::with_capacity(kinds.len());
+    for (ident, kind) in &kinds {
+        match kind.size() {
+            Some(size) => size_terms.push(quote! { #size }),
+            None => {
+                return syn::Error::new_spanned(
+                    ident,
+                    "derive(Account) fields must be fixed-size (Vec<u8> is not supported here)",
+                )
+                .to_compile_error()
+                .into()
+            }
+        }
+    }
+
+    let pack_stmts: Vec<_> = kinds
+        .iter()
+        .map(|(ident, kind)| kind.pack_expr(&quote! { self.#ident }))
+        .collect();
+
+    let mut offset = quote! { 0usize };
+    let mut unpack_stmts = Vec::with_capacity(kinds.len());
+    for (ident, kind) in &kinds {
+        let value = kind.unpack_expr(&offset);
+        unpack_stmts.push(quote! { let #ident = #value; });
+        let size = kind.size().unwrap();
+        offset = quote! { (#offset + #size) };
+    }
+    let field_idents: Vec<_> = kinds.iter().map(|(ident, _)| ident).collect();
+
+    let name_str = name.to_string();
+
+    let pda_impl = derive_pda_impl(&input, name);
+
+    let expanded = quote! {
+        impl ::sutils::discriminator::HashedAccountDiscriminator for #name {
+            const DISCRIMINATOR: [u8; 8] = ::sutils::discriminator::account_discriminator(#name_str);
+        }
+
+        impl ::sutils::account::HashedAccountSerialize for #name {
+            const SERIALIZED_SIZE: usize = 8 #(+ #size_terms)*;
+
+            fn to_bytes_inner(&self) -> Vec<u8> {
+                let mut buf = Vec::with_capacity(Self::SERIALIZED_SIZE - 8);
+                #(#pack_stmts)*
+                buf
+            }
+        }
+
+        impl ::sutils::account::HashedAccountDeserialize for #name {
+            fn from_bytes(data: &[u8]) -> Self {
+                #(#unpack_stmts)*
+                Self { #(#field_idents),* }
+            }
+        }
+
+        impl ::sutils::account::HashedAccountWrite for #name {}
+
+        #pda_impl
+    };
+
+    expanded.into()
+}
+
+fn derive_pda_impl(input: &DeriveInput, name: &syn::Ident) -> TokenStream2 {
+    let Some(attr) = input.attrs.iter().find(|a| a.path().is_ident("seeds")) else {
+        return quote! {};
+    };
+
+    let seeds = match attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+        Ok(seeds) => seeds,
+        Err(err) => return err.to_compile_error(),
+    };
+    let seeds = seeds.iter();
+
+    quote! {
+        impl ::sutils::account::PdaDeriver for #name {
+            fn create_pda(&self) -> ::solana_program::pubkey::Pubkey {
+                Self::pda_derive(&[#(#seeds),*]).0
+            }
+        }
+    }
+}
+
+/// Derives `InstructionDiscriminator` and `InstructionPacker` for an enum, using the variant's
+/// declaration-order index as its `u8` discriminator and serializing each variant's named fields,
+/// in declaration order, after that discriminator byte. A variant's last field may be `Vec<u8>` to
+/// consume the remainder of the instruction data (e.g. an arbitrary message), as in the
+/// hand-written `Hello { msg: Vec<u8> }` variant in `sutils::instruction_packer`'s test module.
+#[proc_macro_derive(Instruction)]
+pub fn derive_instruction(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return syn::Error::new_spanned(name, "derive(Instruction) only supports enums")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let mut discriminator_arms = Vec::with_capacity(variants.len());
+    let mut pack_arms = Vec::with_capacity(variants.len());
+    let mut unpack_arms = Vec::with_capacity(variants.len());
+
+    for (index, variant) in variants.iter().enumerate() {
+        let variant_ident = &variant.ident;
+        let index = index as u8;
+
+        let named = match &variant.fields {
+            Fields::Named(named) => &named.named,
+            Fields::Unit => {
+                discriminator_arms.push(quote! { Self::#variant_ident {} => #index, });
+                pack_arms.push(quote! {
+                    Self::#variant_ident {} => {
+                        vec![#index]
+                    }
+                });
+                unpack_arms.push(quote! { #index => Ok(Self::#variant_ident {}), });
+                continue;
+            }
+            _ => {
+                return syn::Error::new_spanned(
+                    variant_ident,
+                    "derive(Instruction) variants must use named fields",
+                )
+                .to_compile_error()
+                .into()
+            }
+        };
+
+        let mut kinds = Vec::with_capacity(named.len());
+        for (field_index, field) in named.iter().enumerate() {
+            let is_last = field_index + 1 == named.len();
+            match classify(&field.ty) {
+                Ok(FieldKind::Bytes) if !is_last => {
+                    return syn::Error::new_spanned(
+                        &field.ident,
+                        "Vec<u8> is only supported as a variant's last field",
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+                Ok(kind) => kinds.push((field.ident.clone().unwrap(), kind)),
+                Err(err) => return err.into(),
+            }
+        }
+
+        let field_idents: Vec<_> = kinds.iter().map(|(ident, _)| ident.clone()).collect();
+        discriminator_arms.push(quote! {
+            Self::#variant_ident { .. } => #index,
+        });
+
+        let pack_stmts: Vec<_> = kinds
+            .iter()
+            .map(|(ident, kind)| kind.pack_expr(&quote! { (*#ident) }))
+            .collect();
+        pack_arms.push(quote! {
+            Self::#variant_ident { #(#field_idents),* } => {
+                let mut buf = vec![#index];
+                #(#pack_stmts)*
+                buf
+            }
+        });
+
+        let mut offset = quote! { 0usize };
+        let mut unpack_stmts = Vec::with_capacity(kinds.len());
+        for (ident, kind) in &kinds {
+            let value = kind.unpack_expr(&offset);
+            unpack_stmts.push(quote! { let #ident = #value; });
+            if let Some(size) = kind.size() {
+                offset = quote! { (#offset + #size) };
+            }
+        }
+        unpack_arms.push(quote! {
+            #index => {
+                let data = rest;
+                #(#unpack_stmts)*
+                Ok(Self::#variant_ident { #(#field_idents),* })
+            }
+        });
+    }
+
+    let expanded = quote! {
+        impl ::sutils::discriminator::InstructionDiscriminator for #name {
+            fn discriminator(&self) -> u8 {
+                match self {
+                    #(#discriminator_arms)*
+                }
+            }
+        }
+
+        impl ::sutils::instruction_packer::InstructionPacker for #name {
+            fn pack(&self) -> Vec<u8> {
+                match self {
+                    #(#pack_arms)*
+                }
+            }
+
+            fn unpack(data: &[u8]) -> Result<Self, ::solana_program::program_error::ProgramError> {
+                let (first, rest) = data
+                    .split_first()
+                    .ok_or(::solana_program::program_error::ProgramError::InvalidInstructionData)?;
+                match *first {
+                    #(#unpack_arms)*
+                    _ => Err(::solana_program::program_error::ProgramError::InvalidInstructionData),
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
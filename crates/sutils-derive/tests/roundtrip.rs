@@ -0,0 +1,91 @@
+use {
+    solana_program::pubkey::Pubkey,
+    sutils::{
+        account::{HashedAccountDeserialize, HashedAccountSerialize, PdaDeriver, ProgramId},
+        discriminator::HashedAccountDiscriminator,
+        instruction_packer::InstructionPacker,
+    },
+    sutils_derive::{Account, Instruction},
+};
+
+#[derive(Account, Debug, PartialEq, Eq, Clone)]
+#[seeds(b"vault", self.owner.as_ref())]
+pub struct Vault {
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+    pub active: bool,
+    pub nonce: [u8; 4],
+}
+
+impl ProgramId for Vault {
+    const PROGRAM_ID: Pubkey =
+        solana_program::pubkey!("GgBaCs3NCBuZN12kCJgAW63ydqohFkHEdfdEXBPzLHq");
+}
+
+#[derive(Instruction, Debug, PartialEq, Eq, Clone)]
+pub enum VaultInstruction {
+    Initialize { amount: u64 },
+    Close {},
+    Log { msg: Vec<u8> },
+}
+
+#[test]
+fn test_derived_account_round_trips() {
+    let vault = Vault {
+        owner: Pubkey::new_unique(),
+        amount: 1337,
+        bump: 255,
+        active: true,
+        nonce: [1, 2, 3, 4],
+    };
+
+    let bytes = vault.to_bytes().unwrap();
+    assert_eq!(bytes.len(), Vault::SERIALIZED_SIZE);
+    assert_eq!(&bytes[0..8], &Vault::DISCRIMINATOR);
+
+    let decoded = Vault::try_from_bytes(&bytes).unwrap();
+    assert_eq!(vault, decoded);
+}
+
+#[test]
+fn test_derived_account_discriminator_matches_hand_derivation() {
+    assert_eq!(
+        Vault::DISCRIMINATOR,
+        sutils::discriminator::account_discriminator("Vault")
+    );
+}
+
+#[test]
+fn test_derived_pda_matches_manual_seeds() {
+    let vault = Vault {
+        owner: Pubkey::new_unique(),
+        amount: 0,
+        bump: 0,
+        active: false,
+        nonce: [0; 4],
+    };
+
+    let (expected_pda, _bump) = Vault::pda_derive(&[b"vault", vault.owner.as_ref()]);
+    assert_eq!(vault.create_pda(), expected_pda);
+}
+
+#[test]
+fn test_derived_instruction_round_trips() {
+    let initialize = VaultInstruction::Initialize { amount: 42 };
+    let packed = initialize.pack();
+    assert_eq!(packed[0], 0);
+    assert_eq!(VaultInstruction::unpack(&packed).unwrap(), initialize);
+
+    let close = VaultInstruction::Close {};
+    let packed = close.pack();
+    assert_eq!(packed[0], 1);
+    assert_eq!(VaultInstruction::unpack(&packed).unwrap(), close);
+
+    let log = VaultInstruction::Log {
+        msg: b"hello".to_vec(),
+    };
+    let packed = log.pack();
+    assert_eq!(packed[0], 2);
+    assert_eq!(VaultInstruction::unpack(&packed).unwrap(), log);
+}
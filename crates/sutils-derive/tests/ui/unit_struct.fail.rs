@@ -0,0 +1,6 @@
+use sutils_derive::Account;
+
+#[derive(Account)]
+pub struct Bad;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use sutils_derive::Account;
+
+#[derive(Account)]
+pub struct Bad {
+    pub values: [u64; 4],
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use sutils_derive::Account;
+
+#[derive(Account)]
+pub struct Bad {
+    pub name: String,
+}
+
+fn main() {}
@@ -1,7 +1,11 @@
 //! Traits for serialization/deserialization of accounts, and writing serialized account data to [`AccountInfo`]
 
 use {
-    crate::discriminator::AccountDiscriminator,
+    crate::discriminator::{AccountDiscriminator, HashedAccountDiscriminator},
+    core::{
+        cell::{Ref, RefMut},
+        mem::size_of,
+    },
     solana_program::{
         account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
         pubkey::Pubkey,
@@ -109,6 +113,93 @@ pub trait AccountRead: AccountDeserialize + PdaDeriver + Sized {
     }
 }
 
+/// Mirrors [`AccountSerialize`], but prefixes the account with an 8-byte [`HashedAccountDiscriminator`]
+/// instead of a single `u8`
+pub trait HashedAccountSerialize: HashedAccountDiscriminator {
+    /// Defines the serialized size of the account (fields + discriminator)
+    const SERIALIZED_SIZE: usize;
+
+    /// Serializes the struct, prefixed with the discriminator
+    ///
+    /// Used for off-chain/testing
+    fn to_bytes(&self) -> Result<Vec<u8>, ProgramError> {
+        let mut data = vec![0u8; Self::SERIALIZED_SIZE];
+
+        self.into_bytes(&mut data)?;
+
+        Ok(data)
+    }
+
+    /// Similar to [`HashedAccountSerialize::to_bytes`], but avoids vec allocations
+    ///
+    /// Intended for use with on-chain serialization
+    fn into_bytes(&self, buffer: &mut [u8]) -> Result<(), ProgramError> {
+        if buffer.len() < Self::SERIALIZED_SIZE {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        buffer[0..8].copy_from_slice(&Self::DISCRIMINATOR);
+        buffer[8..].copy_from_slice(&self.to_bytes_inner());
+
+        Ok(())
+    }
+
+    /// Serializes the struct, without the discriminator prefix
+    fn to_bytes_inner(&self) -> Vec<u8>;
+}
+
+/// Mirrors [`AccountDeserialize`], but validates an 8-byte [`HashedAccountDiscriminator`] instead
+/// of a single `u8`
+pub trait HashedAccountDeserialize: HashedAccountDiscriminator + Sized {
+    /// Deserializes the given bytes, first validating that the discriminator matches
+    fn try_from_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 8 || data[0..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        Ok(Self::from_bytes(&data[8..]))
+    }
+    fn from_bytes(data: &[u8]) -> Self;
+}
+
+/// Mirrors [`AccountWrite`] for types using the [`HashedAccountSerialize`] scheme
+pub trait HashedAccountWrite: HashedAccountSerialize + Sized {
+    /// Writes the serialized account (with discriminator)
+    fn account_write(self, account_info: &AccountInfo) -> ProgramResult {
+        let mut data = account_info.try_borrow_mut_data()?;
+
+        self.account_write_into(&mut data[..Self::SERIALIZED_SIZE])
+    }
+
+    /// Writes the serialized account (with discriminator) into an arbitrary buffer
+    fn account_write_into(self, buffer: &mut [u8]) -> Result<(), ProgramError> {
+        self.into_bytes(buffer)
+    }
+}
+
+/// Mirrors [`AccountRead`] for types using the [`HashedAccountDeserialize`] scheme
+pub trait HashedAccountRead: HashedAccountDeserialize + PdaDeriver + Sized {
+    /// Reads account data, validating the account discriminator, program owner, and address. See
+    /// [`AccountRead::account_read`] for the rationale behind the address validation.
+    fn account_read(account_info: &AccountInfo) -> Result<Self, ProgramError> {
+        // validate the account owner
+        if account_info.owner.ne(&Self::PROGRAM_ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        // deserialize the account and validate the discriminator
+        let account = Self::try_from_bytes(&account_info.try_borrow_data()?)?;
+
+        // validate the account address
+        let expected_pda = account.create_pda();
+        if account_info.key.ne(&expected_pda) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(account)
+    }
+}
+
 /// The PdaDeriver trait is used to define how to derive a PDA for a specific account
 pub trait PdaDeriver: ProgramId {
     /// Derives a PDA from the provided seeds
@@ -124,12 +215,113 @@ pub trait ProgramId {
     const PROGRAM_ID: Pubkey;
 }
 
+/// Marker trait for types that are safe to view directly over raw account bytes: valid for any
+/// bit pattern, with no padding bytes, matching what `bytemuck::Pod` requires. Implemented by
+/// hand for the POD types this crate cares about rather than pulling in `bytemuck` as a
+/// dependency, same as [`crate::sha256`] hand-rolls SHA-256 instead of pulling in a hashing crate.
+///
+/// Deliberately excludes `u128`: [`AccountLoad::load`]/[`AccountLoad::load_mut`] only guarantee
+/// their view starts 8-byte aligned (Solana's account-buffer alignment guarantee, paired with the
+/// 8-byte [`HashedAccountDiscriminator`] prefix), but `u128` requires 16-byte alignment, so a `Pod`
+/// struct containing one would be reinterpreted at a potentially-misaligned offset.
+///
+/// # Safety
+/// Implementors must be `#[repr(C)]` (or a primitive), contain no padding bytes, be valid for any
+/// bit pattern, and have an alignment of at most 8 bytes. Notably this excludes `bool`, which is
+/// only valid as `0` or `1`.
+pub unsafe trait Pod: Copy {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for Pubkey {}
+unsafe impl<const N: usize> Pod for [u8; N] {}
+
+/// Mirrors [`HashedAccountRead`], but instead of materializing an owned copy of the account via
+/// [`HashedAccountDeserialize::from_bytes`], borrows the account's data buffer directly and
+/// returns a `Ref`/`RefMut` reinterpreting it in place as `Self`. Since the returned view derefs
+/// straight to `&Self`/`&mut Self`, an instruction that only needs to bump a single `u64` counter
+/// can mutate that field in place, instead of round-tripping the whole struct through a `Vec` via
+/// [`HashedAccountSerialize::to_bytes_inner`]/[`HashedAccountDeserialize::from_bytes`].
+///
+/// Implementors must be `#[repr(C)]` and composed entirely of [`Pod`] fields. Pairing with
+/// [`HashedAccountDiscriminator`]'s 8-byte prefix keeps the struct that follows 8-byte aligned,
+/// since Solana guarantees account data buffers are themselves 8-byte aligned.
+pub trait AccountLoad: HashedAccountDiscriminator + PdaDeriver + Pod + Sized {
+    /// Borrows the account data read-only, validating the discriminator, program owner, and PDA
+    /// address (see [`AccountRead::account_read`] for the address-validation rationale), and
+    /// returns a `Ref` reinterpreting the bytes following the discriminator as `&Self`
+    fn load<'a>(account_info: &'a AccountInfo) -> Result<Ref<'a, Self>, ProgramError> {
+        if account_info.owner.ne(&Self::PROGRAM_ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data = account_info.try_borrow_data()?;
+        if data.len() < 8 + size_of::<Self>() || data[0..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let view = Ref::map(data, |data| unsafe {
+            cast_ref::<Self>(&data[8..8 + size_of::<Self>()])
+        });
+
+        if view.create_pda().ne(account_info.key) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(view)
+    }
+
+    /// Borrows the account data mutably, validating the discriminator, program owner, and PDA
+    /// address, and returns a `RefMut` reinterpreting the bytes following the discriminator as
+    /// `&mut Self`
+    fn load_mut<'a>(account_info: &'a AccountInfo) -> Result<RefMut<'a, Self>, ProgramError> {
+        if account_info.owner.ne(&Self::PROGRAM_ID) {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+
+        let data = account_info.try_borrow_mut_data()?;
+        if data.len() < 8 + size_of::<Self>() || data[0..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let view = RefMut::map(data, |data| unsafe {
+            cast_mut::<Self>(&mut data[8..8 + size_of::<Self>()])
+        });
+
+        if view.create_pda().ne(account_info.key) {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        Ok(view)
+    }
+}
+
+/// # Safety
+/// `bytes` must be at least `size_of::<T>()` long, and its start must be aligned to `align_of::<T>()`
+unsafe fn cast_ref<T: Pod>(bytes: &[u8]) -> &T {
+    // `AccountLoad`'s callers only guarantee an 8-byte aligned start (see `Pod`'s doc comment), so
+    // any `T` requiring more than that would make the `as *const T` cast below unsound.
+    const { assert!(core::mem::align_of::<T>() <= 8) };
+
+    &*(bytes.as_ptr() as *const T)
+}
+
+/// # Safety
+/// `bytes` must be at least `size_of::<T>()` long, and its start must be aligned to `align_of::<T>()`
+unsafe fn cast_mut<T: Pod>(bytes: &mut [u8]) -> &mut T {
+    const { assert!(core::mem::align_of::<T>() <= 8) };
+
+    &mut *(bytes.as_mut_ptr() as *mut T)
+}
+
 #[cfg(test)]
 mod test {
     use {
         super::*,
         crate::{account::AccountDeserialize, uint::parse_u64},
-        solana_program::pubkey::Pubkey,
+        solana_program::{pubkey, pubkey::Pubkey},
         std::str::FromStr,
     };
 
@@ -217,4 +409,214 @@ mod test {
     fn test_account_deserialize_invalid_discriminator() {
         FooBar::try_from_bytes(&[4, 2, 0]).unwrap();
     }
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub struct HashedFooBar {
+        pub key: Pubkey,
+        pub amount: u64,
+    }
+
+    impl HashedAccountDiscriminator for HashedFooBar {
+        const DISCRIMINATOR: [u8; 8] = crate::discriminator::account_discriminator("HashedFooBar");
+    }
+
+    impl HashedAccountSerialize for HashedFooBar {
+        const SERIALIZED_SIZE: usize = 8 // discriminator
+            + 32 // key
+            + 8; // amount
+
+        fn to_bytes_inner(&self) -> Vec<u8> {
+            let mut buf = Vec::with_capacity(40);
+
+            buf.extend_from_slice(&self.key.to_bytes());
+            buf.extend_from_slice(&self.amount.to_le_bytes());
+
+            buf
+        }
+    }
+
+    impl HashedAccountDeserialize for HashedFooBar {
+        fn from_bytes(data: &[u8]) -> Self {
+            let key: Pubkey = data[0..32].try_into().expect("insufficient bytes");
+            let amount = parse_u64(&data[32..]);
+
+            Self { key, amount }
+        }
+    }
+
+    impl HashedAccountWrite for HashedFooBar {}
+
+    #[test]
+    fn test_hashed_account_serialize() {
+        let foo_bar = HashedFooBar {
+            key: Pubkey::from_str("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi").unwrap(),
+            amount: 555_000_111_222,
+        };
+
+        let foo_bar_bytes = foo_bar.to_bytes().unwrap();
+
+        assert_eq!(&foo_bar_bytes[0..8], &HashedFooBar::DISCRIMINATOR);
+
+        let decoded_foobar = HashedFooBar::try_from_bytes(&foo_bar_bytes).unwrap();
+
+        assert_eq!(foo_bar, decoded_foobar);
+    }
+
+    #[test]
+    fn test_hashed_account_write() {
+        let foo_bar = HashedFooBar {
+            key: Pubkey::from_str("4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi").unwrap(),
+            amount: 555_000_111_222,
+        };
+        let mut buffer = [0u8; HashedFooBar::SERIALIZED_SIZE];
+
+        foo_bar.clone().account_write_into(&mut buffer).unwrap();
+
+        let decoded_foobar = HashedFooBar::try_from_bytes(&buffer).unwrap();
+
+        assert_eq!(foo_bar, decoded_foobar);
+    }
+
+    #[test]
+    #[should_panic(expected = "InvalidAccountData")]
+    fn test_hashed_account_deserialize_invalid_discriminator() {
+        HashedFooBar::try_from_bytes(&[4, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    }
+
+    #[repr(C)]
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    pub struct LoadFooBar {
+        pub key: Pubkey,
+        pub amount: u64,
+    }
+
+    unsafe impl Pod for LoadFooBar {}
+
+    impl HashedAccountDiscriminator for LoadFooBar {
+        const DISCRIMINATOR: [u8; 8] = crate::discriminator::account_discriminator("LoadFooBar");
+    }
+
+    impl ProgramId for LoadFooBar {
+        const PROGRAM_ID: Pubkey = pubkey!("8qbHbw2BbbTHBW1sbeqakYXVKRQM8Ne7pLK7m6CVfeR");
+    }
+
+    impl PdaDeriver for LoadFooBar {
+        fn create_pda(&self) -> Pubkey {
+            Self::pda_derive(&[b"load_foo_bar", self.key.as_ref()]).0
+        }
+    }
+
+    impl AccountLoad for LoadFooBar {}
+
+    fn load_foo_bar_account_info<'a>(
+        key: &'a Pubkey,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(
+            key,
+            false,
+            false,
+            lamports,
+            data,
+            &LoadFooBar::PROGRAM_ID,
+            false,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_account_load_reads_fields_in_place() {
+        let foo_bar = LoadFooBar {
+            key: Pubkey::from_str("gBxS1f6uyyGPuW5MzGBukidSb71jdsCb5fZaoSzULE5").unwrap(),
+            amount: 1337,
+        };
+        let pda = foo_bar.create_pda();
+
+        let mut data = vec![0u8; 8 + size_of::<LoadFooBar>()];
+        data[0..8].copy_from_slice(&LoadFooBar::DISCRIMINATOR);
+        data[8..40].copy_from_slice(&foo_bar.key.to_bytes());
+        data[40..48].copy_from_slice(&foo_bar.amount.to_le_bytes());
+
+        let mut lamports = 0;
+        let account_info = load_foo_bar_account_info(&pda, &mut lamports, &mut data);
+
+        let loaded = LoadFooBar::load(&account_info).unwrap();
+
+        assert_eq!(*loaded, foo_bar);
+    }
+
+    #[test]
+    fn test_account_load_mut_mutates_in_place() {
+        let foo_bar = LoadFooBar {
+            key: Pubkey::from_str("gBxS1f6uyyGPuW5MzGBukidSb71jdsCb5fZaoSzULE5").unwrap(),
+            amount: 1337,
+        };
+        let pda = foo_bar.create_pda();
+
+        let mut data = vec![0u8; 8 + size_of::<LoadFooBar>()];
+        data[0..8].copy_from_slice(&LoadFooBar::DISCRIMINATOR);
+        data[8..40].copy_from_slice(&foo_bar.key.to_bytes());
+        data[40..48].copy_from_slice(&foo_bar.amount.to_le_bytes());
+
+        let mut lamports = 0;
+        let account_info = load_foo_bar_account_info(&pda, &mut lamports, &mut data);
+
+        {
+            let mut loaded = LoadFooBar::load_mut(&account_info).unwrap();
+            loaded.amount += 1;
+        }
+
+        let reloaded = LoadFooBar::load(&account_info).unwrap();
+        assert_eq!(reloaded.amount, 1338);
+    }
+
+    #[test]
+    fn test_account_load_invalid_discriminator() {
+        let foo_bar = LoadFooBar {
+            key: Pubkey::from_str("gBxS1f6uyyGPuW5MzGBukidSb71jdsCb5fZaoSzULE5").unwrap(),
+            amount: 1337,
+        };
+        let pda = foo_bar.create_pda();
+
+        let mut data = vec![0u8; 8 + size_of::<LoadFooBar>()];
+
+        let mut lamports = 0;
+        let account_info = load_foo_bar_account_info(&pda, &mut lamports, &mut data);
+
+        assert_eq!(
+            LoadFooBar::load(&account_info).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_account_load_invalid_owner() {
+        let foo_bar = LoadFooBar {
+            key: Pubkey::from_str("gBxS1f6uyyGPuW5MzGBukidSb71jdsCb5fZaoSzULE5").unwrap(),
+            amount: 1337,
+        };
+        let pda = foo_bar.create_pda();
+
+        let mut data = vec![0u8; 8 + size_of::<LoadFooBar>()];
+        data[0..8].copy_from_slice(&LoadFooBar::DISCRIMINATOR);
+
+        let mut lamports = 0;
+        let other_owner = Pubkey::new_unique();
+        let account_info = AccountInfo::new(
+            &pda,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &other_owner,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            LoadFooBar::load(&account_info).err(),
+            Some(ProgramError::InvalidAccountOwner)
+        );
+    }
 }
@@ -0,0 +1,237 @@
+//! Helpers for issuing cross-program invocations (CPIs)
+//!
+//! Builds a `solana_program::instruction::Instruction` from a typed [`InstructionPacker`] plus
+//! caller-supplied account metas, and issues it via `invoke`/`invoke_signed`.
+//! [`CrossProgramInvocation::invoke_as_pda`] additionally re-derives a PDA's bump via
+//! [`PdaDeriver::pda_derive`] and assembles the `&[&[&[u8]]]` signer-seeds array, so a program
+//! that owns a PDA can sign CPIs without hand-rolling seed slices.
+
+use {
+    crate::{
+        account::{PdaDeriver, ProgramId},
+        instruction_packer::InstructionPacker,
+    },
+    solana_program::{
+        account_info::AccountInfo,
+        entrypoint::ProgramResult,
+        instruction::{AccountMeta, Instruction},
+        program::{invoke, invoke_signed},
+    },
+};
+
+/// The CrossProgramInvocation trait builds and issues CPIs for any [`InstructionPacker`]-typed
+/// instruction, addressed to the program identified by a [`ProgramId`] implementation `Target`
+pub trait CrossProgramInvocation: InstructionPacker {
+    /// Builds the underlying `Instruction`: program id from `Target::PROGRAM_ID`, data from
+    /// [`InstructionPacker::pack`], accounts supplied by the caller
+    fn instruction<Target: ProgramId>(&self, accounts: &[AccountMeta]) -> Instruction {
+        Instruction {
+            program_id: Target::PROGRAM_ID,
+            accounts: accounts.to_vec(),
+            data: self.pack(),
+        }
+    }
+
+    /// Issues the instruction via `solana_program::program::invoke`
+    fn invoke<Target: ProgramId>(
+        &self,
+        accounts: &[AccountMeta],
+        account_infos: &[AccountInfo],
+    ) -> ProgramResult {
+        invoke(&self.instruction::<Target>(accounts), account_infos)
+    }
+
+    /// Issues the instruction via `solana_program::program::invoke_signed`, signing as the PDA
+    /// owned by `P`, re-deriving its bump from `seeds` via [`PdaDeriver::pda_derive`]
+    fn invoke_as_pda<Target: ProgramId, P: PdaDeriver>(
+        &self,
+        accounts: &[AccountMeta],
+        account_infos: &[AccountInfo],
+        seeds: &[&[u8]],
+    ) -> ProgramResult {
+        let (_pda, bump) = P::pda_derive(seeds);
+
+        self.invoke_as_pda_with_bump::<Target>(accounts, account_infos, seeds, bump)
+    }
+
+    /// Like [`CrossProgramInvocation::invoke_as_pda`], but reuses a `bump` already derived
+    /// elsewhere (e.g. the bump returned from [`crate::processor::InstructionProcessor::validations`])
+    /// instead of re-deriving it
+    fn invoke_as_pda_with_bump<Target: ProgramId>(
+        &self,
+        accounts: &[AccountMeta],
+        account_infos: &[AccountInfo],
+        seeds: &[&[u8]],
+        bump: u8,
+    ) -> ProgramResult {
+        let bump_seed = [bump];
+
+        let mut signer_seeds: Vec<&[u8]> = seeds.to_vec();
+        signer_seeds.push(&bump_seed);
+
+        invoke_signed(
+            &self.instruction::<Target>(accounts),
+            account_infos,
+            &[&signer_seeds],
+        )
+    }
+}
+
+impl<T: InstructionPacker> CrossProgramInvocation for T {}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::discriminator::InstructionDiscriminator,
+        solana_program::{
+            program_error::ProgramError,
+            program_stubs::{set_syscall_stubs, SyscallStubs},
+            pubkey,
+            pubkey::Pubkey,
+        },
+        std::{
+            str::FromStr,
+            sync::{Arc, Mutex},
+        },
+    };
+
+    /// The `Instruction` and owned `signers_seeds` captured by [`CapturingStubs`]
+    type CapturedInvokeSigned = (Instruction, Vec<Vec<Vec<u8>>>);
+
+    /// A `SyscallStubs` that records the `Instruction` and `signers_seeds` passed to
+    /// `invoke_signed`, instead of issuing a real CPI, so `invoke_as_pda`/`invoke_as_pda_with_bump`
+    /// can be exercised as ordinary native unit tests
+    #[derive(Default)]
+    struct CapturingStubs {
+        captured: Arc<Mutex<Option<CapturedInvokeSigned>>>,
+    }
+
+    impl SyscallStubs for CapturingStubs {
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            _account_infos: &[AccountInfo],
+            signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            let owned_seeds = signers_seeds
+                .iter()
+                .map(|seeds| seeds.iter().map(|seed| seed.to_vec()).collect())
+                .collect();
+            *self.captured.lock().unwrap() = Some((instruction.clone(), owned_seeds));
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum TestInstruction {
+        Transfer { amount: u64 },
+    }
+
+    impl InstructionDiscriminator for TestInstruction {
+        fn discriminator(&self) -> u8 {
+            match self {
+                Self::Transfer { .. } => 0,
+            }
+        }
+    }
+
+    impl InstructionPacker for TestInstruction {
+        fn pack(&self) -> Vec<u8> {
+            match self {
+                Self::Transfer { amount } => {
+                    let mut buf = Vec::with_capacity(9);
+                    buf.push(self.discriminator());
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                    buf
+                }
+            }
+        }
+        fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+            let (first, rest) = data
+                .split_first()
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            match first {
+                0 => Ok(Self::Transfer {
+                    amount: u64::from_le_bytes(rest.try_into().expect("insufficient bytes")),
+                }),
+                _ => Err(ProgramError::InvalidInstructionData),
+            }
+        }
+    }
+
+    pub struct TokenProgram {}
+
+    impl ProgramId for TokenProgram {
+        const PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    }
+
+    pub struct Vault {}
+
+    impl ProgramId for Vault {
+        const PROGRAM_ID: Pubkey = pubkey!("CktRuQ2mttgRGkXJtyksdKHjUdc2C4TgDzyB98oEzy8");
+    }
+
+    impl PdaDeriver for Vault {
+        fn create_pda(&self) -> Pubkey {
+            Self::pda_derive(&[b"vault"]).0
+        }
+    }
+
+    #[test]
+    fn test_instruction_builds_expected_shape() {
+        let ix = TestInstruction::Transfer { amount: 1337 };
+        let accounts = vec![AccountMeta::new(Pubkey::new_unique(), false)];
+
+        let built = ix.instruction::<TokenProgram>(&accounts);
+
+        assert_eq!(built.program_id, TokenProgram::PROGRAM_ID);
+        assert_eq!(built.accounts, accounts);
+        assert_eq!(built.data, ix.pack());
+    }
+
+    // `set_syscall_stubs` replaces a single process-wide stub, so installing one here and calling
+    // `invoke_as_pda`/`invoke_as_pda_with_bump` from two separate #[test] fns would race against
+    // `cargo test`'s default parallelism (one test's CPI could get captured by the other's stub).
+    // Both calls are exercised sequentially in this single test instead.
+    #[test]
+    fn test_invoke_as_pda_signer_seeds_include_bump() {
+        let captured = Arc::new(Mutex::new(None));
+        set_syscall_stubs(Box::new(CapturingStubs {
+            captured: captured.clone(),
+        }));
+
+        let ix = TestInstruction::Transfer { amount: 1337 };
+        let (_pda, bump) = Vault::pda_derive(&[b"vault"]);
+
+        ix.invoke_as_pda::<TokenProgram, Vault>(&[], &[], &[b"vault"])
+            .unwrap();
+
+        let (invoked, signer_seeds) = captured.lock().unwrap().take().unwrap();
+        assert_eq!(invoked.program_id, TokenProgram::PROGRAM_ID);
+        assert_eq!(invoked.data, ix.pack());
+        assert_eq!(signer_seeds, vec![vec![b"vault".to_vec(), vec![bump]]]);
+
+        // invoke_as_pda_with_bump reuses an already-derived bump instead of re-deriving it; assert
+        // it assembles the same seeds-with-bump shape
+        let explicit_bump = 7u8;
+
+        ix.invoke_as_pda_with_bump::<TokenProgram>(&[], &[], &[b"vault"], explicit_bump)
+            .unwrap();
+
+        let (invoked, signer_seeds) = captured.lock().unwrap().take().unwrap();
+        assert_eq!(invoked.data, ix.pack());
+        assert_eq!(
+            signer_seeds,
+            vec![vec![b"vault".to_vec(), vec![explicit_bump]]]
+        );
+    }
+
+    #[test]
+    fn test_pubkey_from_str_matches_pubkey_macro() {
+        assert_eq!(
+            TokenProgram::PROGRAM_ID,
+            Pubkey::from_str("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA").unwrap()
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Anchor-esque account and instruction discriminator
 
+use crate::sha256::{self, MAX_INPUT_LEN};
+
 /// The AccountDiscriminator trait is used to uniquely identify accounts
 pub trait AccountDiscriminator {
     const DISCRIMINATOR: u8;
@@ -10,6 +12,81 @@ pub trait InstructionDiscriminator {
     fn discriminator(&self) -> u8;
 }
 
+/// The HashedAccountDiscriminator trait is an opt-in alternative to [`AccountDiscriminator`] that
+/// uses Anchor's 8-byte `sha256("account:" + StructName)` scheme instead of a single `u8`.
+///
+/// The `u8` scheme caps a program at 256 distinct account types, and collides trivially with
+/// accounts belonging to other programs since there is no namespacing by name. Hashing the struct
+/// name the way Anchor does makes a collision astronomically unlikely, which matters for
+/// [`crate::account::AccountRead::account_read`]'s address-validation defense: an attacker can no
+/// longer hand-craft an account from a different program that merely guesses the right byte.
+///
+/// Implementations should derive [`AccountDiscriminator::DISCRIMINATOR`]-equivalent bytes via
+/// [`account_discriminator`], e.g.:
+///
+/// ```ignore
+/// impl HashedAccountDiscriminator for MyAccount {
+///     const DISCRIMINATOR: [u8; 8] = account_discriminator("MyAccount");
+/// }
+/// ```
+pub trait HashedAccountDiscriminator {
+    const DISCRIMINATOR: [u8; 8];
+}
+
+/// The HashedInstructionDiscriminator trait is an opt-in alternative to [`InstructionDiscriminator`]
+/// that uses Anchor's 8-byte `sha256("global:" + snake_case_name)` scheme instead of a single `u8`.
+pub trait HashedInstructionDiscriminator {
+    fn discriminator(&self) -> [u8; 8];
+}
+
+/// Derives an Anchor-compatible account discriminator: the first 8 bytes of `sha256("account:" + name)`
+///
+/// `name` must be the struct name exactly as Anchor would see it (e.g. `"MyAccount"`)
+pub const fn account_discriminator(name: &str) -> [u8; 8] {
+    hashed_discriminator("account:", name)
+}
+
+/// Derives an Anchor-compatible instruction discriminator: the first 8 bytes of `sha256("global:" + name)`
+///
+/// `name` must already be in `snake_case`, matching the instruction's handler function name (e.g.
+/// `"initialize"`)
+pub const fn global_discriminator(name: &str) -> [u8; 8] {
+    hashed_discriminator("global:", name)
+}
+
+const fn hashed_discriminator(prefix: &str, name: &str) -> [u8; 8] {
+    let prefix_bytes = prefix.as_bytes();
+    let name_bytes = name.as_bytes();
+    assert!(
+        prefix_bytes.len() + name_bytes.len() <= MAX_INPUT_LEN,
+        "name too long for hashed discriminator"
+    );
+
+    let mut buf = [0u8; MAX_INPUT_LEN];
+    let mut i = 0;
+    while i < prefix_bytes.len() {
+        buf[i] = prefix_bytes[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < name_bytes.len() {
+        buf[i + j] = name_bytes[j];
+        j += 1;
+    }
+    let total_len = prefix_bytes.len() + name_bytes.len();
+
+    let (preimage, _) = buf.split_at(total_len);
+    let digest = sha256::sha256(preimage);
+
+    let mut out = [0u8; 8];
+    let mut k = 0;
+    while k < 8 {
+        out[k] = digest[k];
+        k += 1;
+    }
+    out
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -26,9 +103,49 @@ mod test {
         }
     }
 
+    impl HashedAccountDiscriminator for FooBar {
+        const DISCRIMINATOR: [u8; 8] = account_discriminator("FooBar");
+    }
+
+    impl HashedInstructionDiscriminator for FooBar {
+        fn discriminator(&self) -> [u8; 8] {
+            global_discriminator("foo_bar")
+        }
+    }
+
     #[test]
     fn test_discriminator() {
-        assert_eq!(1, FooBar::DISCRIMINATOR);
-        assert_eq!(69, FooBar {}.discriminator());
+        assert_eq!(1, <FooBar as AccountDiscriminator>::DISCRIMINATOR);
+        assert_eq!(69, InstructionDiscriminator::discriminator(&FooBar {}));
+    }
+
+    // expected bytes taken from Anchor's own discriminator derivation, confirming this
+    // implementation is wire-compatible
+    #[test]
+    fn test_hashed_account_discriminator_matches_anchor() {
+        assert_eq!(
+            account_discriminator("MyAccount"),
+            [0xf6, 0x1c, 0x06, 0x57, 0xfb, 0x2d, 0x32, 0x2a]
+        );
+    }
+
+    #[test]
+    fn test_hashed_instruction_discriminator_matches_anchor() {
+        assert_eq!(
+            global_discriminator("initialize"),
+            [0xaf, 0xaf, 0x6d, 0x1f, 0x0d, 0x98, 0x9b, 0xed]
+        );
+    }
+
+    #[test]
+    fn test_hashed_discriminator_trait_impls() {
+        assert_eq!(
+            <FooBar as HashedAccountDiscriminator>::DISCRIMINATOR,
+            account_discriminator("FooBar")
+        );
+        assert_eq!(
+            HashedInstructionDiscriminator::discriminator(&FooBar {}),
+            global_discriminator("foo_bar")
+        );
     }
 }
@@ -1,6 +1,9 @@
 //! Instruction packing/unpacking traits
 
-use {crate::discriminator::InstructionDiscriminator, solana_program::program_error::ProgramError};
+use {
+    crate::discriminator::{HashedInstructionDiscriminator, InstructionDiscriminator},
+    solana_program::program_error::ProgramError,
+};
 
 /// The InstructionPacker trait is used to handle packing/unpacking of instruction data
 pub trait InstructionPacker: InstructionDiscriminator + Sized {
@@ -10,6 +13,15 @@ pub trait InstructionPacker: InstructionDiscriminator + Sized {
     fn unpack(data: &[u8]) -> Result<Self, ProgramError>;
 }
 
+/// Mirrors [`InstructionPacker`], but discriminates variants with an 8-byte
+/// [`HashedInstructionDiscriminator`] instead of a single `u8`
+pub trait HashedInstructionPacker: HashedInstructionDiscriminator + Sized {
+    /// Packs the instruction into its raw bytes
+    fn pack(&self) -> Vec<u8>;
+    /// Unpacks raw bytes into typed instruction data
+    fn unpack(data: &[u8]) -> Result<Self, ProgramError>;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -101,4 +113,75 @@ mod test {
 
         TestInstruction::unpack(&data).unwrap();
     }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum HashedTestInstruction {
+        Hello { msg: Vec<u8> },
+        Init { force: bool },
+    }
+
+    impl HashedInstructionDiscriminator for HashedTestInstruction {
+        fn discriminator(&self) -> [u8; 8] {
+            match self {
+                Self::Hello { .. } => crate::discriminator::global_discriminator("hello"),
+                Self::Init { .. } => crate::discriminator::global_discriminator("init"),
+            }
+        }
+    }
+
+    impl HashedInstructionPacker for HashedTestInstruction {
+        fn pack(&self) -> Vec<u8> {
+            match self {
+                Self::Hello { msg } => {
+                    let mut buf = Vec::with_capacity(8 + msg.len());
+                    buf.extend(self.discriminator());
+                    buf.extend(msg);
+                    buf
+                }
+                Self::Init { force } => {
+                    let mut buf = Vec::with_capacity(9);
+                    buf.extend(self.discriminator());
+                    buf.push(if *force { 1 } else { 0 });
+                    buf
+                }
+            }
+        }
+        fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+            if data.len() < 8 {
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            let (discriminator, rest) = data.split_at(8);
+            if discriminator == crate::discriminator::global_discriminator("hello") {
+                Ok(Self::Hello { msg: rest.to_vec() })
+            } else if discriminator == crate::discriminator::global_discriminator("init") {
+                Ok(Self::Init {
+                    force: rest[0] != 0,
+                })
+            } else {
+                Err(ProgramError::InvalidInstructionData)
+            }
+        }
+    }
+
+    #[test]
+    fn test_hashed_packer_hello() {
+        let hello = HashedTestInstruction::Hello {
+            msg: b"foobar".to_vec(),
+        };
+
+        let packed_hello = hello.pack();
+
+        assert_eq!(&packed_hello[0..8], &hello.discriminator());
+
+        let unpacked_hello = HashedTestInstruction::unpack(&packed_hello).unwrap();
+
+        assert_eq!(hello, unpacked_hello);
+    }
+
+    #[test]
+    fn test_hashed_packer_unpack_invalid_instruction() {
+        let data: Vec<u8> = vec![69, 42, 0, 1, 3, 3, 7, 0, 0];
+
+        assert!(HashedTestInstruction::unpack(&data).is_err());
+    }
 }
@@ -0,0 +1,255 @@
+//! Helpers for inspecting sibling instructions via the instructions sysvar
+//!
+//! The instructions sysvar (`Sysvar1nstructions1111111111111111111111111`) lets a program inspect
+//! other instructions within the same transaction. This is most commonly used to require that a
+//! signature-verification instruction (Ed25519/secp256k1) or a token transfer immediately precedes
+//! the instruction currently being processed. This module is a thin, ergonomic wrapper around
+//! `solana_program::sysvar::instructions`, building on top of [`InstructionPacker`] so a caller
+//! can unpack a sibling instruction straight into its typed representation.
+
+use {
+    crate::instruction_packer::InstructionPacker,
+    solana_program::{
+        account_info::AccountInfo, instruction::Instruction, program_error::ProgramError,
+        pubkey::Pubkey, sysvar::instructions as instructions_sysvar,
+    },
+};
+
+/// Returns the index of the instruction currently being processed within the transaction
+pub fn load_current_index(
+    instructions_sysvar_account_info: &AccountInfo,
+) -> Result<u16, ProgramError> {
+    instructions_sysvar::load_current_index_checked(instructions_sysvar_account_info)
+}
+
+/// Loads the raw instruction at `index` within the transaction
+pub fn load_instruction_at(
+    index: usize,
+    instructions_sysvar_account_info: &AccountInfo,
+) -> Result<Instruction, ProgramError> {
+    instructions_sysvar::load_instruction_at_checked(index, instructions_sysvar_account_info)
+}
+
+/// Loads the raw instruction `offset` slots away from the one currently being processed
+///
+/// For example, `offset == -1` loads the instruction immediately preceding the current one
+pub fn get_instruction_relative(
+    offset: i64,
+    instructions_sysvar_account_info: &AccountInfo,
+) -> Result<Instruction, ProgramError> {
+    instructions_sysvar::get_instruction_relative(offset, instructions_sysvar_account_info)
+}
+
+/// Loads the instruction at `index` and [`InstructionPacker::unpack`]s its data into `T`
+pub fn unpack_instruction_at<T: InstructionPacker>(
+    index: usize,
+    instructions_sysvar_account_info: &AccountInfo,
+) -> Result<T, ProgramError> {
+    T::unpack(&load_instruction_at(index, instructions_sysvar_account_info)?.data)
+}
+
+/// Loads the instruction `offset` slots away from the current one and [`InstructionPacker::unpack`]s
+/// its data into `T`
+pub fn unpack_instruction_relative<T: InstructionPacker>(
+    offset: i64,
+    instructions_sysvar_account_info: &AccountInfo,
+) -> Result<T, ProgramError> {
+    T::unpack(&get_instruction_relative(offset, instructions_sysvar_account_info)?.data)
+}
+
+/// Requires that the instruction immediately preceding the current one was issued by `program_id`
+/// and carries `discriminator` as the first byte of its instruction data
+///
+/// Fits alongside [`crate::processor::InstructionProcessor::validations`] — a processor can use
+/// this to require, for example, that a secp256k1 signature-verify instruction immediately
+/// precedes it.
+pub fn require_preceding_instruction(
+    instructions_sysvar_account_info: &AccountInfo,
+    program_id: &Pubkey,
+    discriminator: u8,
+) -> Result<(), ProgramError> {
+    let preceding = get_instruction_relative(-1, instructions_sysvar_account_info)?;
+
+    if preceding.program_id.ne(program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if preceding.data.first().copied() != Some(discriminator) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::discriminator::InstructionDiscriminator,
+        solana_program::{
+            instruction::AccountMeta,
+            pubkey::Pubkey,
+            sysvar::instructions::{construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction},
+        },
+    };
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub enum TestInstruction {
+        Init { force: bool },
+    }
+
+    impl InstructionDiscriminator for TestInstruction {
+        fn discriminator(&self) -> u8 {
+            match self {
+                Self::Init { .. } => 1,
+            }
+        }
+    }
+
+    impl InstructionPacker for TestInstruction {
+        fn pack(&self) -> Vec<u8> {
+            match self {
+                Self::Init { force } => vec![self.discriminator(), if *force { 1 } else { 0 }],
+            }
+        }
+        fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+            let (first, rest) = data
+                .split_first()
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            match first {
+                1 => Ok(Self::Init {
+                    force: rest[0] != 0,
+                }),
+                _ => Err(ProgramError::InvalidInstructionData),
+            }
+        }
+    }
+
+    /// Builds a mocked instructions-sysvar account buffer containing `instructions`, with the
+    /// current-instruction index set to `current_index`
+    fn mock_instructions_sysvar_data(
+        instructions: &[(Pubkey, Vec<u8>)],
+        current_index: u16,
+    ) -> Vec<u8> {
+        let borrowed: Vec<BorrowedInstruction> = instructions
+            .iter()
+            .map(|(program_id, data)| BorrowedInstruction {
+                program_id,
+                accounts: Vec::<BorrowedAccountMeta>::new(),
+                data,
+            })
+            .collect();
+
+        let mut data = construct_instructions_data(&borrowed);
+        instructions_sysvar::store_current_index(&mut data, current_index);
+        data
+    }
+
+    #[test]
+    fn test_load_current_index_and_instruction_at() {
+        let secp256k1_program = Pubkey::new_unique();
+        let our_program = Pubkey::new_unique();
+
+        let secp256k1_ix_data = vec![9, 9, 9];
+        let init_ix = TestInstruction::Init { force: true };
+
+        let mut data = mock_instructions_sysvar_data(
+            &[
+                (secp256k1_program, secp256k1_ix_data.clone()),
+                (our_program, init_ix.pack()),
+            ],
+            1,
+        );
+
+        let key = instructions_sysvar::id();
+        let owner = solana_program::sysvar::id();
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert_eq!(load_current_index(&account_info).unwrap(), 1);
+
+        let loaded_secp256k1 = load_instruction_at(0, &account_info).unwrap();
+        assert_eq!(loaded_secp256k1.program_id, secp256k1_program);
+        assert_eq!(loaded_secp256k1.data, secp256k1_ix_data);
+        assert_eq!(loaded_secp256k1.accounts, Vec::<AccountMeta>::new());
+
+        let unpacked: TestInstruction = unpack_instruction_at(1, &account_info).unwrap();
+        assert_eq!(unpacked, init_ix);
+    }
+
+    #[test]
+    fn test_require_preceding_instruction() {
+        let secp256k1_program = Pubkey::new_unique();
+        let our_program = Pubkey::new_unique();
+
+        let mut data = mock_instructions_sysvar_data(
+            &[
+                (secp256k1_program, vec![1, 2, 3]),
+                (our_program, TestInstruction::Init { force: true }.pack()),
+            ],
+            1,
+        );
+
+        let key = instructions_sysvar::id();
+        let owner = solana_program::sysvar::id();
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert!(require_preceding_instruction(&account_info, &secp256k1_program, 1).is_ok());
+        assert_eq!(
+            require_preceding_instruction(&account_info, &our_program, 1),
+            Err(ProgramError::IncorrectProgramId)
+        );
+        assert_eq!(
+            require_preceding_instruction(&account_info, &secp256k1_program, 2),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_require_preceding_instruction_absent() {
+        let our_program = Pubkey::new_unique();
+
+        let mut data = mock_instructions_sysvar_data(
+            &[(our_program, TestInstruction::Init { force: true }.pack())],
+            0,
+        );
+
+        let key = instructions_sysvar::id();
+        let owner = solana_program::sysvar::id();
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+
+        assert_eq!(
+            require_preceding_instruction(&account_info, &our_program, 1),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+}
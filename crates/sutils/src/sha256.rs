@@ -0,0 +1,168 @@
+//! A `const fn` SHA-256 implementation
+//!
+//! Anchor-compatible discriminators are derived from a hash of a struct/instruction name, and we
+//! want that derivation to happen at compile time (see [`crate::discriminator::account_discriminator`]
+//! and [`crate::discriminator::global_discriminator`]), so the digest itself has to be computable
+//! in a `const` context. This is a plain from-scratch implementation of the algorithm rather than
+//! a dependency, since no `const fn` sha256 crate is pulled in elsewhere in this workspace.
+
+const BLOCK_SIZE: usize = 64;
+const MAX_BLOCKS: usize = 8;
+
+/// The longest message this implementation can hash, dictated by [`MAX_BLOCKS`]
+///
+/// Comfortably large enough for `"account:" + StructName` / `"global:" + instruction_name` inputs
+pub(crate) const MAX_INPUT_LEN: usize = MAX_BLOCKS * BLOCK_SIZE - 9;
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Hashes `input` with SHA-256, evaluable at compile time
+///
+/// Panics (at compile time, via `const` panic) if `input` is longer than [`MAX_INPUT_LEN`]
+pub(crate) const fn sha256(input: &[u8]) -> [u8; 32] {
+    assert!(
+        input.len() <= MAX_INPUT_LEN,
+        "input too long for const sha256"
+    );
+
+    let mut buf = [0u8; MAX_BLOCKS * BLOCK_SIZE];
+    let mut i = 0;
+    while i < input.len() {
+        buf[i] = input[i];
+        i += 1;
+    }
+    buf[i] = 0x80;
+
+    let bit_len = (input.len() as u64) * 8;
+    let total_len = input.len() + 1 + 8;
+    let num_blocks = total_len.div_ceil(BLOCK_SIZE);
+    let padded_len = num_blocks * BLOCK_SIZE;
+
+    let mut j = 0;
+    while j < 8 {
+        buf[padded_len - 1 - j] = ((bit_len >> (8 * j)) & 0xff) as u8;
+        j += 1;
+    }
+
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+
+    let mut block = 0;
+    while block < num_blocks {
+        let base = block * BLOCK_SIZE;
+        let mut w = [0u32; 64];
+        let mut t = 0;
+        while t < 16 {
+            let off = base + t * 4;
+            w[t] = ((buf[off] as u32) << 24)
+                | ((buf[off + 1] as u32) << 16)
+                | ((buf[off + 2] as u32) << 8)
+                | (buf[off + 3] as u32);
+            t += 1;
+        }
+        while t < 64 {
+            let s0 = w[t - 15].rotate_right(7) ^ w[t - 15].rotate_right(18) ^ (w[t - 15] >> 3);
+            let s1 = w[t - 2].rotate_right(17) ^ w[t - 2].rotate_right(19) ^ (w[t - 2] >> 10);
+            w[t] = w[t - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[t - 7])
+                .wrapping_add(s1);
+            t += 1;
+        }
+
+        let (mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut hh) =
+            (h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7]);
+
+        let mut t = 0;
+        while t < 64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ ((!e) & g);
+            let temp1 = hh
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(K[t])
+                .wrapping_add(w[t]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            hh = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+
+            t += 1;
+        }
+
+        h[0] = h[0].wrapping_add(a);
+        h[1] = h[1].wrapping_add(b);
+        h[2] = h[2].wrapping_add(c);
+        h[3] = h[3].wrapping_add(d);
+        h[4] = h[4].wrapping_add(e);
+        h[5] = h[5].wrapping_add(f);
+        h[6] = h[6].wrapping_add(g);
+        h[7] = h[7].wrapping_add(hh);
+
+        block += 1;
+    }
+
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 8 {
+        let bytes = h[i].to_be_bytes();
+        out[i * 4] = bytes[0];
+        out[i * 4 + 1] = bytes[1];
+        out[i * 4 + 2] = bytes[2];
+        out[i * 4 + 3] = bytes[3];
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sha256_abc() {
+        let digest = sha256(b"abc");
+
+        assert_eq!(
+            digest,
+            [
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sha256_empty() {
+        let digest = sha256(b"");
+
+        assert_eq!(
+            digest,
+            [
+                0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+                0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+                0x78, 0x52, 0xb8, 0x55,
+            ]
+        );
+    }
+}